@@ -12,7 +12,9 @@
 //! * Toroidal grid implementation
 
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseButton, MouseEventKind,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -20,11 +22,15 @@ use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
     Terminal,
 };
 use std::{
-    error::Error, 
+    collections::{hash_map::DefaultHasher, BTreeSet, HashMap, VecDeque},
+    error::Error,
+    fs,
+    hash::{Hash, Hasher},
     io,
     time::{Duration, Instant},
     process,
@@ -32,6 +38,184 @@ use std::{
 use rand::Rng;
 use sysinfo::{System, SystemExt};
 
+/// Number of recent generation hashes kept for stagnation/cycle detection.
+const STAGNATION_HISTORY: usize = 64;
+
+/// Default path used by the `s`/`l` key bindings to save and load patterns.
+const DEFAULT_PATTERN_FILE: &str = "pattern.rle";
+
+/// Default number of dead cells revived per re-seed when `--seed-population` is omitted.
+const DEFAULT_SEED_POPULATION: usize = 10;
+
+/// Amount the `[`/`]` key bindings adjust the re-seed interval by.
+const SEED_INTERVAL_STEP: usize = 10;
+
+/// Number of cells the arrow keys pan the sparse-engine viewport by.
+const VIEWPORT_PAN_STEP: i64 = 5;
+
+/// Selects which grid engine `App::new` constructs.
+#[derive(Debug, Clone, Copy)]
+enum EngineKind {
+    /// A bounded `width * height` board, double-buffered to avoid per-tick allocation.
+    Dense,
+    /// An unbounded board that only stores live cells, viewed through a scrollable viewport.
+    Sparse,
+}
+
+impl EngineKind {
+    /// Parses an engine name, defaulting to `Dense` for anything but `"sparse"`.
+    fn parse(s: &str) -> EngineKind {
+        match s {
+            "sparse" => EngineKind::Sparse,
+            _ => EngineKind::Dense,
+        }
+    }
+}
+
+/// The simulation's cell storage, selected by `--engine` at startup.
+#[derive(Debug)]
+enum Grid {
+    /// Two `width * height` age buffers swapped each generation; `front`
+    /// indicates which one holds the current generation.
+    Dense {
+        buffers: [Vec<Vec<u16>>; 2],
+        front: usize,
+    },
+    /// Only live cell coordinates are stored, so the universe is effectively
+    /// unbounded; `view_x`/`view_y` is the world coordinate shown at the
+    /// top-left of the rendered viewport. `ages` mirrors `live`, tracking how
+    /// many consecutive generations each live cell has survived so the
+    /// sparse engine can render the same age gradient as the dense one.
+    Sparse {
+        live: BTreeSet<(i64, i64)>,
+        ages: HashMap<(i64, i64), u16>,
+        view_x: i64,
+        view_y: i64,
+    },
+}
+
+/// Command-line arguments understood by the application.
+struct CliArgs {
+    /// Pattern file to load at startup, if any
+    file: Option<String>,
+    /// Birth/survival ruleset in B/S notation, if any (defaults to Conway's B3/S23)
+    rule: Option<String>,
+    /// Re-seed every N generations, if any (0/absent disables re-seeding)
+    seed_interval: Option<usize>,
+    /// Approximate number of dead cells to revive on each re-seed
+    seed_population: Option<usize>,
+    /// Grid engine to use: `"dense"` (default) or `"sparse"`
+    engine: Option<String>,
+}
+
+impl CliArgs {
+    /// Parses `--file`/`-f`, `--rule`/`-r`, `--seed-interval`/`-i`,
+    /// `--seed-population`/`-p`, and `--engine`/`-e` options from the process
+    /// arguments.
+    ///
+    /// A bare positional argument (no matching flag) is treated as the pattern
+    /// file, for convenience.
+    fn parse() -> CliArgs {
+        let mut result = CliArgs {
+            file: None,
+            rule: None,
+            seed_interval: None,
+            seed_population: None,
+            engine: None,
+        };
+        let mut args = std::env::args().skip(1);
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--file" | "-f" => result.file = args.next(),
+                "--rule" | "-r" => result.rule = args.next(),
+                "--seed-interval" | "-i" => {
+                    result.seed_interval = args.next().and_then(|s| s.parse().ok());
+                }
+                "--seed-population" | "-p" => {
+                    result.seed_population = args.next().and_then(|s| s.parse().ok());
+                }
+                "--engine" | "-e" => {
+                    result.engine = args.next();
+                }
+                _ => result.file = result.file.or(Some(arg)),
+            }
+        }
+
+        result
+    }
+}
+
+/// A cellular-automaton rule expressed in B/S (birth/survival) notation.
+///
+/// `birth[n]`/`survive[n]` indicate whether a dead/live cell with `n` live
+/// neighbors is born or survives, e.g. Conway's Game of Life is `B3/S23`.
+#[derive(Debug, Clone)]
+struct Rule {
+    /// `birth[n]` is true if a dead cell with `n` live neighbors is born
+    birth: [bool; 9],
+    /// `survive[n]` is true if a live cell with `n` live neighbors survives
+    survive: [bool; 9],
+    /// The notation this rule was parsed from, e.g. "B3/S23"
+    notation: String,
+}
+
+impl Rule {
+    /// The standard Conway's Game of Life rule, B3/S23.
+    fn conway() -> Rule {
+        Rule::parse("B3/S23").expect("B3/S23 is a valid rule")
+    }
+
+    /// Parses a rule string in B/S notation, e.g. `"B3/S23"` or `"B36/S23"` (HighLife).
+    ///
+    /// # Arguments
+    ///
+    /// * `s` - The rule string, as `B<digits>/S<digits>`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string isn't in `B<digits>/S<digits>` form.
+    fn parse(s: &str) -> Result<Rule, Box<dyn Error>> {
+        let mut birth = [false; 9];
+        let mut survive = [false; 9];
+
+        let (b_part, s_part) = s
+            .split_once('/')
+            .ok_or_else(|| format!("invalid rule '{}': expected B.../S...", s))?;
+
+        let b_digits = b_part
+            .strip_prefix(['B', 'b'])
+            .ok_or_else(|| format!("invalid rule '{}': birth part must start with 'B'", s))?;
+        let s_digits = s_part
+            .strip_prefix(['S', 's'])
+            .ok_or_else(|| format!("invalid rule '{}': survival part must start with 'S'", s))?;
+
+        for c in b_digits.chars() {
+            let n = c
+                .to_digit(10)
+                .ok_or_else(|| format!("invalid rule '{}': non-digit in birth part", s))?;
+            if n > 8 {
+                return Err(format!("invalid rule '{}': birth digit '{}' out of range 0-8", s, n).into());
+            }
+            birth[n as usize] = true;
+        }
+        for c in s_digits.chars() {
+            let n = c
+                .to_digit(10)
+                .ok_or_else(|| format!("invalid rule '{}': non-digit in survival part", s))?;
+            if n > 8 {
+                return Err(format!("invalid rule '{}': survival digit '{}' out of range 0-8", s, n).into());
+            }
+            survive[n as usize] = true;
+        }
+
+        Ok(Rule {
+            birth,
+            survive,
+            notation: s.to_string(),
+        })
+    }
+}
 
 /// Stores statistics about the Game of Life simulation.
 #[derive(Debug)]
@@ -44,6 +228,10 @@ struct Stats {
     cells_destroyed: u64,
     /// Current number of living cells
     current_population: u64,
+    /// The oscillation period detected once the simulation has stabilized
+    stable_period: Option<u64>,
+    /// The generation at which stabilization was detected
+    stable_generation: Option<u64>,
 }
 
 impl Stats {
@@ -54,18 +242,21 @@ impl Stats {
             cells_created: 0,
             cells_destroyed: 0,
             current_population: 0,
+            stable_period: None,
+            stable_generation: None,
         }
     }
 }
 
 /// Main application state container for the Game of Life simulation.
-#[derive(Debug)] 
+#[derive(Debug)]
 struct App {
-    /// The game board represented as a 2D vector of booleans where true indicates a live cell
-    grid: Vec<Vec<bool>>,
-    /// Width of the game board
+    /// The game board, either a dense double-buffered grid or a sparse set of
+    /// live cells, depending on which `--engine` was selected
+    grid: Grid,
+    /// Width of the game board (and, in sparse mode, of the rendered viewport)
     width: usize,
-    /// Height of the game board
+    /// Height of the game board (and, in sparse mode, of the rendered viewport)
     height: usize,
     /// Indicates whether the simulation is currently running
     running: bool,
@@ -73,27 +264,69 @@ struct App {
     stats: Stats,
     /// System information for resource monitoring
     sys: System,
+    /// Path used by the `s`/`l` key bindings to save/load the current pattern
+    pattern_path: String,
+    /// The birth/survival ruleset used by `update`
+    rule: Rule,
+    /// Hashes of the last `STAGNATION_HISTORY` generations, paired with their
+    /// generation number, used to detect still lifes and oscillators
+    history: VecDeque<(u64, u64)>,
+    /// Re-seed every N generations when > 0; 0 disables periodic re-seeding
+    seed_interval: usize,
+    /// Approximate number of dead cells to revive on each re-seed
+    seed_population: usize,
 }
 
 impl App {
     /// Creates a new Game of Life application with the specified dimensions.
-    /// 
+    ///
     /// # Arguments
-    /// 
+    ///
     /// * `width` - The width of the game board
     /// * `height` - The height of the game board
-    /// 
+    /// * `rule` - The birth/survival ruleset to simulate
+    /// * `seed_interval` - Re-seed every N generations (0 disables re-seeding)
+    /// * `seed_population` - Approximate number of dead cells to revive per re-seed
+    ///
+    /// * `engine` - Which grid engine to construct (dense or sparse)
+    ///
     /// # Returns
-    /// 
+    ///
     /// A new `App` instance with a randomly initialized grid where approximately 30%
     /// of cells are alive.
-
-    
-    fn new(width: usize, height: usize) -> App {
+    fn new(
+        width: usize,
+        height: usize,
+        rule: Rule,
+        seed_interval: usize,
+        seed_population: usize,
+        engine: EngineKind,
+    ) -> App {
         let mut rng = rand::thread_rng();
-        let grid = (0..height)
-            .map(|_| (0..width).map(|_| rng.gen_bool(0.3)).collect())
-            .collect();
+        let grid = match engine {
+            EngineKind::Dense => {
+                let buffer: Vec<Vec<u16>> = (0..height)
+                    .map(|_| (0..width).map(|_| if rng.gen_bool(0.3) { 1 } else { 0 }).collect())
+                    .collect();
+                Grid::Dense {
+                    buffers: [buffer.clone(), buffer],
+                    front: 0,
+                }
+            }
+            EngineKind::Sparse => {
+                let live: BTreeSet<(i64, i64)> = (0..height as i64)
+                    .flat_map(|y| (0..width as i64).map(move |x| (x, y)))
+                    .filter(|_| rng.gen_bool(0.3))
+                    .collect();
+                let ages = live.iter().map(|&cell| (cell, 1)).collect();
+                Grid::Sparse {
+                    live,
+                    ages,
+                    view_x: 0,
+                    view_y: 0,
+                }
+            }
+        };
 
         let mut app = App {
             grid,
@@ -102,130 +335,675 @@ impl App {
             running: false,
             stats: Stats::new(),
             sys: System::new_all(),
+            pattern_path: DEFAULT_PATTERN_FILE.to_string(),
+            rule,
+            history: VecDeque::new(),
+            seed_interval,
+            seed_population,
         };
-        
+
         // Calculate initial population
         app.stats.current_population = app.count_total_alive();
+        let hash = app.hash_grid();
+        app.history.push_back((0, hash));
         app
     }
-    
+
+    /// Loads a pattern from a plaintext or RLE file, replacing the current grid.
+    ///
+    /// The grid is cleared first, then the decoded pattern is placed centered on
+    /// the toroidal grid; any cells that would fall outside the grid bounds are
+    /// skipped. The format is chosen by file extension: files ending in `.rle`
+    /// are parsed as run-length encoded patterns, everything else is parsed as
+    /// plaintext (`.`/` `/`0` dead, anything else alive).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to the pattern file to load
+    fn load_file(&mut self, path: &str) -> Result<(), Box<dyn Error>> {
+        let contents = fs::read_to_string(path)?;
+        let pattern = if path.ends_with(".rle") {
+            parse_rle(&contents)?
+        } else {
+            parse_plaintext(&contents)
+        };
+
+        let pattern_height = pattern.len();
+        let pattern_width = pattern.iter().map(|row| row.len()).max().unwrap_or(0);
+        let offset_y = (self.height as isize - pattern_height as isize) / 2;
+        let offset_x = (self.width as isize - pattern_width as isize) / 2;
+
+        match &mut self.grid {
+            Grid::Dense { buffers, front } => {
+                for cell in buffers[*front].iter_mut().flatten() {
+                    *cell = 0;
+                }
+
+                for (py, row) in pattern.iter().enumerate() {
+                    for (px, &alive) in row.iter().enumerate() {
+                        if !alive {
+                            continue;
+                        }
+
+                        let gx = offset_x + px as isize;
+                        let gy = offset_y + py as isize;
+                        if gx < 0 || gy < 0 || gx as usize >= self.width || gy as usize >= self.height {
+                            continue;
+                        }
+
+                        buffers[*front][gy as usize][gx as usize] = 1;
+                    }
+                }
+            }
+            Grid::Sparse { live, ages, view_x, view_y } => {
+                live.clear();
+                ages.clear();
+                *view_x = 0;
+                *view_y = 0;
+
+                for (py, row) in pattern.iter().enumerate() {
+                    for (px, &alive) in row.iter().enumerate() {
+                        if !alive {
+                            continue;
+                        }
+
+                        let gx = (offset_x + px as isize) as i64;
+                        let gy = (offset_y + py as isize) as i64;
+                        live.insert((gx, gy));
+                        ages.insert((gx, gy), 1);
+                    }
+                }
+            }
+        }
+
+        self.stats.current_population = self.count_total_alive();
+        self.pattern_path = path.to_string();
+
+        // Loading a pattern starts a fresh run, so past stagnation history no longer applies.
+        self.stats.stable_period = None;
+        self.stats.stable_generation = None;
+        self.history.clear();
+        let hash = self.hash_grid();
+        self.history.push_back((self.stats.generation, hash));
+
+        Ok(())
+    }
+
+    /// Saves the current grid to `path` in RLE format.
+    ///
+    /// Always writes RLE, so if `path` doesn't already end in `.rle` the
+    /// extension is appended and `self.pattern_path` is updated to match;
+    /// otherwise a later `load_file` would mis-detect the file as plaintext.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path to write the pattern to
+    fn save_file(&mut self, path: &str) -> Result<(), Box<dyn Error>> {
+        let rle_path = if path.ends_with(".rle") {
+            path.to_string()
+        } else {
+            format!("{}.rle", path)
+        };
+        fs::write(&rle_path, self.to_rle())?;
+        self.pattern_path = rle_path;
+        Ok(())
+    }
+
+    /// Encodes the current grid as a standard RLE pattern string.
+    fn to_rle(&self) -> String {
+        match &self.grid {
+            Grid::Dense { buffers, front } => rows_to_rle(&buffers[*front], self.width, self.height),
+            Grid::Sparse { live, .. } => sparse_to_rle(live),
+        }
+    }
+
     /// Counts the total number of living cells in the grid.
-    /// 
+    ///
     /// # Returns
-    /// 
+    ///
     /// The number of cells that are currently alive.
     fn count_total_alive(&self) -> u64 {
-        self.grid.iter()
-            .flat_map(|row| row.iter())
-            .filter(|&&cell| cell)
-            .count() as u64
+        match &self.grid {
+            Grid::Dense { buffers, front } => buffers[*front]
+                .iter()
+                .flat_map(|row| row.iter())
+                .filter(|&&age| age > 0)
+                .count() as u64,
+            Grid::Sparse { live, .. } => live.len() as u64,
+        }
     }
-    
-    /// Updates the grid to the next generation according to Conway's Game of Life rules:
-    /// 
-    /// * Any live cell with fewer than two live neighbors dies (underpopulation)
-    /// * Any live cell with two or three live neighbors survives
-    /// * Any live cell with more than three live neighbors dies (overpopulation)
-    /// * Any dead cell with exactly three live neighbors becomes alive (reproduction)
+
+    /// Hashes the set of currently-live cell coordinates.
+    ///
+    /// Used by `update` to detect still lifes and oscillators: two
+    /// generations with the same live cells hash identically regardless of
+    /// cell age, so a repeated hash means the simulation has entered a cycle.
+    fn hash_grid(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        match &self.grid {
+            Grid::Dense { buffers, front } => {
+                for (y, row) in buffers[*front].iter().enumerate() {
+                    for (x, &age) in row.iter().enumerate() {
+                        if age > 0 {
+                            (x, y).hash(&mut hasher);
+                        }
+                    }
+                }
+            }
+            Grid::Sparse { live, .. } => {
+                for cell in live {
+                    cell.hash(&mut hasher);
+                }
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Randomly revives roughly `self.seed_population` dead cells, counting
+    /// them as created. Called periodically from `update` to keep long runs
+    /// from collapsing into static debris.
+    fn reseed(&mut self) {
+        let mut rng = rand::thread_rng();
+        let mut seeded = 0;
+        match &mut self.grid {
+            Grid::Dense { buffers, front } => {
+                for _ in 0..self.seed_population {
+                    let x = rng.gen_range(0..self.width);
+                    let y = rng.gen_range(0..self.height);
+                    if buffers[*front][y][x] == 0 {
+                        buffers[*front][y][x] = 1;
+                        seeded += 1;
+                    }
+                }
+            }
+            Grid::Sparse { live, ages, view_x, view_y } => {
+                for _ in 0..self.seed_population {
+                    let x = *view_x + rng.gen_range(0..self.width as i64);
+                    let y = *view_y + rng.gen_range(0..self.height as i64);
+                    if live.insert((x, y)) {
+                        ages.insert((x, y), 1);
+                        seeded += 1;
+                    }
+                }
+            }
+        }
+        self.stats.cells_created += seeded;
+    }
+
+    /// Updates the grid to the next generation according to `self.rule`:
+    ///
+    /// * A live cell survives if its live neighbor count is in `rule.survive`,
+    ///   with its age incremented
+    /// * A dead cell is born if its live neighbor count is in `rule.birth`,
+    ///   starting at age 1
+    /// * Otherwise a live cell dies (age reset to 0) and a dead cell stays dead
     fn update(&mut self) {
-        let mut new_grid = self.grid.clone();
+        let (cells_created, cells_destroyed) = match self.grid {
+            Grid::Dense { .. } => self.step_dense(),
+            Grid::Sparse { .. } => self.step_sparse(),
+        };
+
+        self.stats.generation += 1;
+        self.stats.cells_created += cells_created;
+        self.stats.cells_destroyed += cells_destroyed;
+
+        if self.seed_interval > 0 && self.stats.generation.is_multiple_of(self.seed_interval as u64) {
+            self.reseed();
+        }
+
+        self.stats.current_population = self.count_total_alive();
+        self.sys.refresh_memory();
+
+        let hash = self.hash_grid();
+        if let Some(&(matched_generation, _)) =
+            self.history.iter().rev().find(|&&(_, h)| h == hash)
+        {
+            self.stats.stable_period = Some(self.stats.generation - matched_generation);
+            self.stats.stable_generation = Some(self.stats.generation);
+            // Periodic re-seeding is meant to keep long runs going unattended,
+            // so don't let stagnation auto-stop fight it: without re-seeding,
+            // stop as before; with it, stay running and let the next re-seed
+            // perturb the board out of its cycle.
+            if self.seed_interval == 0 {
+                self.running = false;
+            }
+        } else {
+            // The cycle (if any) was broken by a re-seed or edit, so the
+            // stats panel shouldn't keep reporting a stale stabilization.
+            self.stats.stable_period = None;
+            self.stats.stable_generation = None;
+        }
+
+        self.history.push_back((self.stats.generation, hash));
+        if self.history.len() > STAGNATION_HISTORY {
+            self.history.pop_front();
+        }
+    }
+
+    /// Advances a `Grid::Dense` board by one generation in place, reading the
+    /// `front` buffer and writing the other one, then flipping `front` — this
+    /// reuses both buffers across the application's lifetime instead of
+    /// cloning the grid every tick.
+    ///
+    /// # Returns
+    ///
+    /// `(cells_created, cells_destroyed)` for the generation just computed.
+    fn step_dense(&mut self) -> (u64, u64) {
         let mut cells_created = 0;
         let mut cells_destroyed = 0;
 
+        let Grid::Dense { buffers, front } = &mut self.grid else {
+            unreachable!("step_dense called on a non-dense grid");
+        };
+        let read = *front;
+        let write = 1 - *front;
+        let (read_buf, write_buf) = if read == 0 {
+            let (a, b) = buffers.split_at_mut(1);
+            (&a[0], &mut b[0])
+        } else {
+            let (a, b) = buffers.split_at_mut(1);
+            (&b[0], &mut a[0])
+        };
+
         for y in 0..self.height {
             for x in 0..self.width {
-                let live_neighbors = self.count_neighbors(x, y);
-                let cell = self.grid[y][x];
-                let new_state = match (cell, live_neighbors) {
-                    (true, x) if x < 2 => {
+                let live_neighbors = count_dense_neighbors(read_buf, self.width, self.height, x, y);
+                let age = read_buf[y][x];
+                let new_age = match age {
+                    age if age > 0 && self.rule.survive[live_neighbors as usize] => age.saturating_add(1),
+                    age if age > 0 => {
                         cells_destroyed += 1;
-                        false
+                        0
                     },
-                    (true, 2) | (true, 3) => true,
-                    (true, x) if x > 3 => {
-                        cells_destroyed += 1;
-                        false
-                    },
-                    (false, 3) => {
+                    _ if self.rule.birth[live_neighbors as usize] => {
                         cells_created += 1;
-                        true
+                        1
                     },
-                    (otherwise, _) => otherwise,
+                    _ => 0,
                 };
-                new_grid[y][x] = new_state;
+                write_buf[y][x] = new_age;
             }
         }
 
-        self.grid = new_grid;
-        self.stats.generation += 1;
-        self.stats.cells_created += cells_created;
-        self.stats.cells_destroyed += cells_destroyed;
-        self.stats.current_population = self.count_total_alive();
-        self.sys.refresh_memory();
+        *front = write;
+        (cells_created, cells_destroyed)
     }
 
-    /// Counts the number of live neighbors for a cell at the specified coordinates.
-    /// 
-    /// The grid is treated as toroidal, meaning the edges wrap around to the opposite side.
-    /// 
-    /// # Arguments
-    /// 
-    /// * `x` - The x-coordinate of the cell
-    /// * `y` - The y-coordinate of the cell
-    /// 
+    /// Advances a `Grid::Sparse` board by one generation: tallies neighbor
+    /// counts only for live cells and their immediate neighbors, then applies
+    /// `self.rule` to exactly those candidate cells instead of scanning a
+    /// bounded grid.
+    ///
     /// # Returns
-    /// 
-    /// The number of live neighbors (0-8)
-    
-    fn count_neighbors(&self, x: usize, y: usize) -> u8 {
-        let mut count = 0;
-        for dy in -1..=1 {
-            for dx in -1..=1 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
+    ///
+    /// `(cells_created, cells_destroyed)` for the generation just computed.
+    fn step_sparse(&mut self) -> (u64, u64) {
+        let Grid::Sparse { live, ages, .. } = &self.grid else {
+            unreachable!("step_sparse called on a non-sparse grid");
+        };
 
-                let nx = (x as i32 + dx).rem_euclid(self.width as i32) as usize;
-                let ny = (y as i32 + dy).rem_euclid(self.height as i32) as usize;
+        let mut neighbor_counts: HashMap<(i64, i64), u8> = HashMap::new();
+        for &cell in live.iter() {
+            neighbor_counts.entry(cell).or_insert(0);
+        }
+        for &(x, y) in live.iter() {
+            for dy in -1..=1i64 {
+                for dx in -1..=1i64 {
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+                    *neighbor_counts.entry((x + dx, y + dy)).or_insert(0) += 1;
+                }
+            }
+        }
 
-                if self.grid[ny][nx] {
-                    count += 1;
+        let mut next = BTreeSet::new();
+        let mut next_ages = HashMap::new();
+        let mut cells_created = 0;
+        let mut cells_destroyed = 0;
+        for (&cell, &count) in neighbor_counts.iter() {
+            let was_alive = live.contains(&cell);
+            let survives = was_alive && self.rule.survive[count as usize];
+            let born = !was_alive && self.rule.birth[count as usize];
+            if survives || born {
+                next.insert(cell);
+                if born {
+                    cells_created += 1;
+                    next_ages.insert(cell, 1);
+                } else {
+                    let age = ages.get(&cell).copied().unwrap_or(1);
+                    next_ages.insert(cell, age.saturating_add(1));
                 }
+            } else if was_alive {
+                cells_destroyed += 1;
             }
         }
-        count
+
+        let Grid::Sparse { live, ages, .. } = &mut self.grid else {
+            unreachable!("step_sparse called on a non-sparse grid");
+        };
+        *live = next;
+        *ages = next_ages;
+        (cells_created, cells_destroyed)
     }
-    
+
+
     /// Toggles the simulation between running and paused states.
     fn toggle_running(&mut self) {
         self.running = !self.running;
     }
+
+    /// Toggles the cell at viewport position `(x, y)`, if in bounds.
+    fn toggle_cell(&mut self, x: usize, y: usize) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        match &mut self.grid {
+            Grid::Dense { buffers, front } => {
+                buffers[*front][y][x] = if buffers[*front][y][x] > 0 { 0 } else { 1 };
+            }
+            Grid::Sparse { live, ages, view_x, view_y } => {
+                let cell = (*view_x + x as i64, *view_y + y as i64);
+                if !live.remove(&cell) {
+                    live.insert(cell);
+                    ages.insert(cell, 1);
+                } else {
+                    ages.remove(&cell);
+                }
+            }
+        }
+        self.stats.current_population = self.count_total_alive();
+    }
+
+    /// Sets the cell at viewport position `(x, y)` alive, if in bounds.
+    fn set_cell_alive(&mut self, x: usize, y: usize) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        match &mut self.grid {
+            Grid::Dense { buffers, front } => {
+                if buffers[*front][y][x] == 0 {
+                    buffers[*front][y][x] = 1;
+                }
+            }
+            Grid::Sparse { live, ages, view_x, view_y } => {
+                let cell = (*view_x + x as i64, *view_y + y as i64);
+                if live.insert(cell) {
+                    ages.insert(cell, 1);
+                }
+            }
+        }
+        self.stats.current_population = self.count_total_alive();
+    }
+
+    /// Pans the sparse-engine viewport by `(dx, dy)` world cells; a no-op on
+    /// a dense grid, which has no separate viewport.
+    fn pan_viewport(&mut self, dx: i64, dy: i64) {
+        if let Grid::Sparse { view_x, view_y, .. } = &mut self.grid {
+            *view_x += dx;
+            *view_y += dy;
+        }
+    }
+
+    /// Sets every cell alive along the straight line from `(x0, y0)` to
+    /// `(x1, y1)` using Bresenham's line algorithm, so a fast mouse drag
+    /// doesn't leave gaps between sampled points.
+    fn draw_line(&mut self, x0: usize, y0: usize, x1: usize, y1: usize) {
+        let (mut x, mut y) = (x0 as i64, y0 as i64);
+        let (x1, y1) = (x1 as i64, y1 as i64);
+        let dx = (x1 - x).abs();
+        let dy = -(y1 - y).abs();
+        let sx = if x < x1 { 1 } else { -1 };
+        let sy = if y < y1 { 1 } else { -1 };
+        let mut err = dx + dy;
+
+        loop {
+            self.set_cell_alive(x as usize, y as usize);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+}
+
+/// Counts the number of live neighbors for a cell in a dense `width * height`
+/// age buffer, wrapping toroidally at the edges.
+///
+/// # Arguments
+///
+/// * `grid` - The age buffer to read
+/// * `width` - Width of the buffer
+/// * `height` - Height of the buffer
+/// * `x` - The x-coordinate of the cell
+/// * `y` - The y-coordinate of the cell
+///
+/// # Returns
+///
+/// The number of live neighbors (0-8)
+fn count_dense_neighbors(grid: &[Vec<u16>], width: usize, height: usize, x: usize, y: usize) -> u8 {
+    let mut count = 0;
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            if dx == 0 && dy == 0 {
+                continue;
+            }
+
+            let nx = (x as i32 + dx).rem_euclid(width as i32) as usize;
+            let ny = (y as i32 + dy).rem_euclid(height as i32) as usize;
+
+            if grid[ny][nx] > 0 {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// Encodes a dense `width * height` age buffer as a standard RLE pattern string.
+fn rows_to_rle(grid: &[Vec<u16>], width: usize, height: usize) -> String {
+    let mut body = String::new();
+    for row in grid.iter().take(height) {
+        let mut runs: Vec<(char, usize)> = Vec::new();
+        for &age in row.iter().take(width) {
+            let tag = if age > 0 { 'o' } else { 'b' };
+            match runs.last_mut() {
+                Some((run_tag, run_len)) if *run_tag == tag => *run_len += 1,
+                _ => runs.push((tag, 1)),
+            }
+        }
+
+        // Trailing dead cells are conventionally omitted before the row terminator.
+        if matches!(runs.last(), Some((tag, _)) if *tag == 'b') {
+            runs.pop();
+        }
+
+        for (tag, len) in runs {
+            if len > 1 {
+                body.push_str(&len.to_string());
+            }
+            body.push(tag);
+        }
+        body.push('$');
+    }
+
+    if body.ends_with('$') {
+        body.pop();
+    }
+    body.push('!');
+
+    format!("x = {}, y = {}\n{}\n", width, height, body)
+}
+
+/// Encodes a sparse live-cell set as an RLE pattern string, relative to its
+/// own bounding box. An empty set encodes as an empty `0 x 0` pattern.
+fn sparse_to_rle(live: &BTreeSet<(i64, i64)>) -> String {
+    let (Some(&min_x), Some(&max_x)) = (
+        live.iter().map(|(x, _)| x).min(),
+        live.iter().map(|(x, _)| x).max(),
+    ) else {
+        return "x = 0, y = 0\n!\n".to_string();
+    };
+    let min_y = *live.iter().map(|(_, y)| y).min().unwrap();
+    let max_y = *live.iter().map(|(_, y)| y).max().unwrap();
+
+    let width = (max_x - min_x + 1) as usize;
+    let height = (max_y - min_y + 1) as usize;
+    let mut grid = vec![vec![0u16; width]; height];
+    for &(x, y) in live {
+        grid[(y - min_y) as usize][(x - min_x) as usize] = 1;
+    }
+
+    rows_to_rle(&grid, width, height)
+}
+
+/// Parses a plaintext-format pattern (one row per line).
+///
+/// A character is dead if it is `.`, ` `, or `0`; any other character is
+/// treated as a live cell.
+///
+/// # Arguments
+///
+/// * `contents` - The raw contents of the pattern file
+fn parse_plaintext(contents: &str) -> Vec<Vec<bool>> {
+    contents
+        .lines()
+        .map(|line| line.chars().map(|c| !matches!(c, '.' | ' ' | '0')).collect())
+        .collect()
+}
+
+/// Parses a pattern in the standard RLE (run-length encoded) format.
+///
+/// Recognizes the `x = W, y = H` header, run tokens `b` (dead) and `o`
+/// (alive), `$` (end of row), and the `!` terminator. Lines starting with
+/// `#` or `x` (the header) are skipped.
+///
+/// # Arguments
+///
+/// * `contents` - The raw contents of the `.rle` file
+///
+/// # Errors
+///
+/// Returns an error if the pattern has no `!` terminator.
+fn parse_rle(contents: &str) -> Result<Vec<Vec<bool>>, Box<dyn Error>> {
+    let mut rows: Vec<Vec<bool>> = vec![Vec::new()];
+    let mut count = String::new();
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('x') {
+            continue;
+        }
+
+        for c in line.chars() {
+            match c {
+                '0'..='9' => count.push(c),
+                'b' | 'o' => {
+                    let n: usize = count.parse().unwrap_or(1);
+                    count.clear();
+                    let alive = c == 'o';
+                    rows.last_mut().unwrap().extend(std::iter::repeat_n(alive, n));
+                }
+                '$' => {
+                    let n: usize = count.parse().unwrap_or(1);
+                    count.clear();
+                    for _ in 0..n {
+                        rows.push(Vec::new());
+                    }
+                }
+                '!' => {
+                    return Ok(rows);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Err("RLE pattern missing '!' terminator".into())
+}
+
+/// Buckets a cell's age into a color on a "hot to cool" gradient: newly born
+/// cells render bright and long-lived cells fade toward a dim, cool color.
+///
+/// # Arguments
+///
+/// * `age` - How many consecutive generations the cell has been alive
+fn age_color(age: u16) -> Color {
+    match age {
+        0 => Color::Reset,
+        1 => Color::Rgb(255, 255, 255),
+        2..=3 => Color::Rgb(255, 210, 60),
+        4..=8 => Color::Rgb(255, 140, 0),
+        9..=20 => Color::Rgb(80, 200, 255),
+        _ => Color::Rgb(40, 90, 160),
+    }
 }
 
 /// Draws the game grid to the terminal interface.
-/// 
+///
 /// # Arguments
-/// 
+///
 /// * `f` - The frame to draw on
 /// * `app` - The application state
 /// * `area` - The area of the terminal to draw in
 fn draw_grid(f: &mut ratatui::Frame, app: &App, area: Rect) {
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .title("Game of Life [Space: Play/Pause | Enter: Step | q: Quit]");
-    
-    let mut cells = String::new();
-    for row in &app.grid {
-        for &cell in row {
-            cells.push(if cell { 'â€¢' } else { ' ' });
+    let title = match &app.grid {
+        Grid::Dense { .. } => {
+            "Game of Life [Space: Play/Pause | Enter: Step | s: Save | l: Load | [/]: Seed Interval | q: Quit]"
+                .to_string()
         }
-        cells.push('\n');
-    }
-    
-    let paragraph = Paragraph::new(cells)
-        .style(Style::default().fg(Color::White))
-        .block(block);
-    
+        Grid::Sparse { view_x, view_y, .. } => format!(
+            "Game of Life [Space: Play/Pause | Enter: Step | s: Save | l: Load | Arrows: Pan | q: Quit] (viewport {}, {})",
+            view_x, view_y
+        ),
+    };
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    let lines: Vec<Line> = match &app.grid {
+        Grid::Dense { buffers, front } => buffers[*front]
+            .iter()
+            .map(|row| {
+                let spans: Vec<Span> = row
+                    .iter()
+                    .map(|&age| {
+                        if age == 0 {
+                            Span::raw(" ")
+                        } else {
+                            Span::styled("•", Style::default().fg(age_color(age)))
+                        }
+                    })
+                    .collect();
+                Line::from(spans)
+            })
+            .collect(),
+        Grid::Sparse { live, ages, view_x, view_y } => (0..app.height)
+            .map(|row| {
+                let spans: Vec<Span> = (0..app.width)
+                    .map(|col| {
+                        let cell = (view_x + col as i64, view_y + row as i64);
+                        if live.contains(&cell) {
+                            let age = ages.get(&cell).copied().unwrap_or(1);
+                            Span::styled("•", Style::default().fg(age_color(age)))
+                        } else {
+                            Span::raw(" ")
+                        }
+                    })
+                    .collect();
+                Line::from(spans)
+            })
+            .collect(),
+    };
+
+    let paragraph = Paragraph::new(lines).block(block);
+
     f.render_widget(paragraph, area);
 }
 
@@ -240,8 +1018,14 @@ fn draw_stats(f: &mut ratatui::Frame, app: &App, area: Rect) {
     let memory_used = app.sys.used_memory() / 1024; // Convert to KB
     let memory_total = app.sys.total_memory() / 1024;
     
+    let status = match (app.stats.stable_period, app.stats.stable_generation) {
+        (Some(period), Some(gen)) => format!("Stabilized (period {} at gen {})", period, gen),
+        _ => if app.running { "Running".to_string() } else { "Paused".to_string() },
+    };
+
     let stats_text = format!(
         "Statistics:\n\
+        Rule: {}\n\
         Generation: {}\n\
         Current Population: {}\n\
         Cells Created: {}\n\
@@ -249,7 +1033,9 @@ fn draw_stats(f: &mut ratatui::Frame, app: &App, area: Rect) {
         Birth Rate: {:.2}/gen\n\
         Death Rate: {:.2}/gen\n\
         Memory Usage: {}KB/{:.2}MB\n\
+        Seed Interval: {} (pop {})\n\
         Status: {}\n",
+        app.rule.notation,
         app.stats.generation,
         app.stats.current_population,
         app.stats.cells_created,
@@ -258,7 +1044,9 @@ fn draw_stats(f: &mut ratatui::Frame, app: &App, area: Rect) {
         app.stats.cells_destroyed as f64 / app.stats.generation.max(1) as f64,
         memory_used,
         memory_total as f64 / 1024.0,
-        if app.running { "Running" } else { "Paused" }
+        app.seed_interval,
+        app.seed_population,
+        status
     );
 
     let stats_widget = Paragraph::new(stats_text)
@@ -274,24 +1062,60 @@ fn draw_stats(f: &mut ratatui::Frame, app: &App, area: Rect) {
 /// The game runs at 10 FPS (100ms intervals) when active.
 /// 
 /// # Controls
-/// 
+///
 /// * Space: Play/Pause the simulation
 /// * Enter: Step forward one generation (when paused)
+/// * s: Save the current pattern
+/// * l: Load a pattern
 /// * q: Quit the application
-/// 
+/// * Left click: toggle a cell; left drag: draw live cells, filling in gaps
+///   between drag samples with Bresenham's line algorithm (works whether the
+///   simulation is running or paused)
+/// * `[`/`]`: decrease/increase the re-seed interval
+/// * Arrow keys: pan the viewport (sparse engine only)
+///
+/// # CLI Arguments
+///
+/// * `--file`/`-f` (or a bare positional argument) selects a plaintext or
+///   `.rle` pattern file to load before the first generation
+/// * `--rule`/`-r` selects a B/S ruleset (default: `B3/S23`, Conway's Game
+///   of Life)
+/// * `--seed-interval`/`-i` re-seeds the board every N generations (default:
+///   0, disabled)
+/// * `--seed-population`/`-p` sets how many dead cells each re-seed revives
+///   (default: 10)
+/// * `--engine`/`-e` selects the grid engine: `"dense"` (default, a bounded
+///   double-buffered board) or `"sparse"` (an unbounded board storing only
+///   live cells, with a scrollable viewport)
+///
 /// # Errors
-/// 
-/// Returns an error if terminal manipulation fails.
+///
+/// Returns an error if terminal manipulation fails, if `--rule` is not
+/// valid B/S notation, or if the `--file` pattern cannot be read or parsed.
 fn main() -> Result<(), Box<dyn Error>> {
+    let cli = CliArgs::parse();
+    let rule = match &cli.rule {
+        Some(r) => Rule::parse(r)?,
+        None => Rule::conway(),
+    };
+    let seed_interval = cli.seed_interval.unwrap_or(0);
+    let seed_population = cli.seed_population.unwrap_or(DEFAULT_SEED_POPULATION);
+    let engine = cli.engine.as_deref().map(EngineKind::parse).unwrap_or(EngineKind::Dense);
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let mut app = App::new(80, 40);
+    let mut app = App::new(80, 40, rule, seed_interval, seed_population, engine);
+    if let Some(path) = &cli.file {
+        app.load_file(path)?;
+    }
     let mut last_tick = Instant::now();
     let tick_rate = Duration::from_millis(100);
+    let mut grid_area = Rect::default();
+    let mut drag_cell: Option<(usize, usize)> = None;
 
     loop {
         terminal.draw(|f| {
@@ -302,7 +1126,8 @@ fn main() -> Result<(), Box<dyn Error>> {
                     Constraint::Percentage(25),
                 ].as_ref())
                 .split(f.size());
-            
+            grid_area = chunks[0];
+
             draw_grid(f, &app, chunks[0]);
             draw_stats(f, &app, chunks[1]);
         })?;
@@ -312,17 +1137,70 @@ fn main() -> Result<(), Box<dyn Error>> {
             .unwrap_or_else(|| Duration::from_secs(0));
 
         if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
+            match event::read()? {
+                Event::Key(key) => match key.code {
                     KeyCode::Char('q') => break,
                     KeyCode::Char(' ') => app.toggle_running(),
+                    KeyCode::Char('s') => {
+                        let _ = app.save_file(&app.pattern_path.clone());
+                    }
+                    KeyCode::Char('l') => {
+                        let _ = app.load_file(&app.pattern_path.clone());
+                    }
+                    KeyCode::Char('[') => {
+                        app.seed_interval = app.seed_interval.saturating_sub(SEED_INTERVAL_STEP);
+                    }
+                    KeyCode::Char(']') => {
+                        app.seed_interval += SEED_INTERVAL_STEP;
+                    }
+                    KeyCode::Left => app.pan_viewport(-VIEWPORT_PAN_STEP, 0),
+                    KeyCode::Right => app.pan_viewport(VIEWPORT_PAN_STEP, 0),
+                    KeyCode::Up => app.pan_viewport(0, -VIEWPORT_PAN_STEP),
+                    KeyCode::Down => app.pan_viewport(0, VIEWPORT_PAN_STEP),
                     KeyCode::Enter => {
                         if !app.running {
                             app.update();
                         }
                     }
                     _ => {}
+                },
+                Event::Mouse(mouse) => {
+                    // The grid border takes up one row/column on each side.
+                    let inner_x = mouse.column as i64 - grid_area.x as i64 - 1;
+                    let inner_y = mouse.row as i64 - grid_area.y as i64 - 1;
+                    let cell = if inner_x >= 0
+                        && inner_y >= 0
+                        && (inner_x as usize) < app.width
+                        && (inner_y as usize) < app.height
+                    {
+                        Some((inner_x as usize, inner_y as usize))
+                    } else {
+                        None
+                    };
+
+                    match mouse.kind {
+                        MouseEventKind::Down(MouseButton::Left) => {
+                            if let Some((x, y)) = cell {
+                                app.toggle_cell(x, y);
+                            }
+                            drag_cell = cell;
+                        }
+                        MouseEventKind::Drag(MouseButton::Left) => {
+                            if let Some((x, y)) = cell {
+                                match drag_cell {
+                                    Some((px, py)) => app.draw_line(px, py, x, y),
+                                    None => app.set_cell_alive(x, y),
+                                }
+                            }
+                            drag_cell = cell;
+                        }
+                        MouseEventKind::Up(MouseButton::Left) => {
+                            drag_cell = None;
+                        }
+                        _ => {}
+                    }
                 }
+                _ => {}
             }
         }
 
@@ -344,3 +1222,211 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_parse_accepts_valid_digits() {
+        let rule = Rule::parse("B3/S23").unwrap();
+        assert!(rule.birth[3]);
+        assert!(rule.survive[2] && rule.survive[3]);
+    }
+
+    #[test]
+    fn rule_parse_rejects_out_of_range_digit() {
+        assert!(Rule::parse("B9/S23").is_err());
+        assert!(Rule::parse("B3/S9").is_err());
+    }
+
+    #[test]
+    fn age_color_buckets_by_age() {
+        assert_eq!(age_color(0), Color::Reset);
+        assert_eq!(age_color(1), Color::Rgb(255, 255, 255));
+        assert_eq!(age_color(3), Color::Rgb(255, 210, 60));
+        assert_eq!(age_color(4), Color::Rgb(255, 140, 0));
+        assert_eq!(age_color(20), Color::Rgb(80, 200, 255));
+        assert_eq!(age_color(21), Color::Rgb(40, 90, 160));
+    }
+
+    #[test]
+    fn parse_plaintext_reads_dots_and_spaces_as_dead() {
+        let pattern = parse_plaintext(".O.\nOO \n.O.");
+        assert_eq!(
+            pattern,
+            vec![
+                vec![false, true, false],
+                vec![true, true, false],
+                vec![false, true, false],
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_rle_decodes_runs_and_row_breaks() {
+        let pattern = parse_rle("x = 3, y = 3\nbob$2ob$bob!\n").unwrap();
+        assert_eq!(
+            pattern,
+            vec![
+                vec![false, true, false],
+                vec![true, true, false],
+                vec![false, true, false],
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_rle_errors_without_terminator() {
+        assert!(parse_rle("x = 1, y = 1\nbo").is_err());
+    }
+
+    #[test]
+    fn rows_to_rle_round_trips_through_parse_rle() {
+        // Every row ends alive so the RLE "drop trailing dead cells"
+        // convention doesn't shorten a row and change its length on decode.
+        let grid = vec![vec![0u16, 1, 1], vec![1, 0, 1], vec![0, 1, 1]];
+        let encoded = rows_to_rle(&grid, 3, 3);
+        let decoded = parse_rle(&encoded).unwrap();
+        let alive: Vec<Vec<bool>> = grid
+            .iter()
+            .map(|row| row.iter().map(|&age| age > 0).collect())
+            .collect();
+        assert_eq!(decoded, alive);
+    }
+
+    #[test]
+    fn sparse_to_rle_encodes_relative_to_bounding_box() {
+        let live: BTreeSet<(i64, i64)> = [(5, 5), (6, 5), (5, 6)].into_iter().collect();
+        let decoded = parse_rle(&sparse_to_rle(&live)).unwrap();
+        assert_eq!(
+            decoded,
+            vec![vec![true, true], vec![true]]
+        );
+    }
+
+    #[test]
+    fn draw_line_sets_every_cell_along_a_horizontal_span() {
+        let mut app = App::new(10, 10, Rule::conway(), 0, 0, EngineKind::Dense);
+        let Grid::Dense { buffers, front } = &mut app.grid else {
+            unreachable!("dense engine requested");
+        };
+        for cell in buffers[*front].iter_mut().flatten() {
+            *cell = 0;
+        }
+
+        app.draw_line(1, 1, 4, 1);
+
+        let Grid::Dense { buffers, front } = &app.grid else {
+            unreachable!("dense engine requested");
+        };
+        for x in 1..=4 {
+            assert!(buffers[*front][1][x] > 0, "cell ({}, 1) should be alive", x);
+        }
+        assert_eq!(buffers[*front][1][0], 0);
+        assert_eq!(buffers[*front][1][5], 0);
+    }
+
+    #[test]
+    fn draw_line_sets_every_cell_along_a_diagonal_span() {
+        let mut app = App::new(10, 10, Rule::conway(), 0, 0, EngineKind::Dense);
+        let Grid::Dense { buffers, front } = &mut app.grid else {
+            unreachable!("dense engine requested");
+        };
+        for cell in buffers[*front].iter_mut().flatten() {
+            *cell = 0;
+        }
+
+        app.draw_line(0, 0, 3, 3);
+
+        let Grid::Dense { buffers, front } = &app.grid else {
+            unreachable!("dense engine requested");
+        };
+        for i in 0..=3 {
+            assert!(buffers[*front][i][i] > 0, "cell ({}, {}) should be alive", i, i);
+        }
+    }
+
+    #[test]
+    fn step_dense_and_step_sparse_agree_on_a_blinker() {
+        // A 20x20 board keeps the blinker far enough from the edges that the
+        // dense engine's toroidal wraparound can't diverge from the sparse
+        // engine's unbounded plane.
+        let mut dense = App::new(20, 20, Rule::conway(), 0, 0, EngineKind::Dense);
+        let mut sparse = App::new(20, 20, Rule::conway(), 0, 0, EngineKind::Sparse);
+
+        if let Grid::Dense { buffers, front } = &mut dense.grid {
+            for cell in buffers[*front].iter_mut().flatten() {
+                *cell = 0;
+            }
+        }
+        if let Grid::Sparse { live, ages, .. } = &mut sparse.grid {
+            live.clear();
+            ages.clear();
+        }
+
+        for &(x, y) in &[(10usize, 9usize), (10, 10), (10, 11)] {
+            dense.set_cell_alive(x, y);
+            sparse.set_cell_alive(x, y);
+        }
+
+        dense.update();
+        sparse.update();
+
+        let dense_live: BTreeSet<(i64, i64)> = match &dense.grid {
+            Grid::Dense { buffers, front } => buffers[*front]
+                .iter()
+                .enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter()
+                        .enumerate()
+                        .filter(|&(_, &age)| age > 0)
+                        .map(move |(x, _)| (x as i64, y as i64))
+                })
+                .collect(),
+            Grid::Sparse { .. } => unreachable!(),
+        };
+        let sparse_live = match &sparse.grid {
+            Grid::Sparse { live, .. } => live.clone(),
+            Grid::Dense { .. } => unreachable!(),
+        };
+
+        assert_eq!(dense_live, sparse_live);
+    }
+
+    #[test]
+    fn update_reports_the_true_minimal_period_for_a_blinker() {
+        let mut app = App::new(20, 20, Rule::conway(), 0, 0, EngineKind::Dense);
+        if let Grid::Dense { buffers, front } = &mut app.grid {
+            for cell in buffers[*front].iter_mut().flatten() {
+                *cell = 0;
+            }
+        }
+        for &(x, y) in &[(10usize, 9usize), (10, 10), (10, 11)] {
+            app.set_cell_alive(x, y);
+        }
+
+        // A blinker oscillates with period 2; scanning history oldest-first
+        // would report a period that grows by 2 every time it's re-checked.
+        for _ in 0..6 {
+            app.update();
+        }
+
+        assert_eq!(app.stats.stable_period, Some(2));
+    }
+
+    #[test]
+    fn reseed_revives_dead_cells_and_counts_them_created() {
+        let mut app = App::new(20, 20, Rule::conway(), 0, 50, EngineKind::Dense);
+        if let Grid::Dense { buffers, front } = &mut app.grid {
+            for cell in buffers[*front].iter_mut().flatten() {
+                *cell = 0;
+            }
+        }
+
+        app.reseed();
+
+        assert!(app.stats.cells_created > 0);
+        assert_eq!(app.stats.cells_created, app.count_total_alive());
+    }
+}